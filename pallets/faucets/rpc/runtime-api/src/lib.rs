@@ -0,0 +1,19 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+use pallet_faucets::FaucetSettings;
+
+sp_api::decl_runtime_apis! {
+    pub trait FaucetApi<AccountId, BlockNumber, Balance> where
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Balance: Codec
+    {
+        fn get_remaining_allowance(faucet: AccountId, recipient: AccountId) -> Balance;
+
+        fn get_next_reset_block(faucet: AccountId, recipient: AccountId) -> Option<BlockNumber>;
+
+        fn get_faucet_settings(faucet: AccountId) -> Option<FaucetSettings<BlockNumber, Balance>>;
+    }
+}
@@ -0,0 +1,105 @@
+//! Benchmarking for pallet_faucets.
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks_instance};
+use frame_support::{dispatch::UnfilteredDispatchable, traits::Currency};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// A generous balance so that funded faucets comfortably clear the minimum balance.
+fn funded_account<T: Trait<I>, I: Instance>(name: &'static str, index: u32) -> T::AccountId {
+    let account: T::AccountId = account(name, index, SEED);
+    let balance = BalanceOf::<T, I>::from(1_000_000_000u32);
+    T::Currency::make_free_balance_be(&account, balance);
+    account
+}
+
+fn default_settings<T: Trait<I>, I: Instance>() -> FaucetSettings<T::BlockNumber, BalanceOf<T, I>> {
+    FaucetSettings {
+        period: Some(100u32.into()),
+        period_limit: 1_000u32.into(),
+        drop_limit: 100u32.into(),
+        vesting: None,
+        allow_partial_drip: false,
+        enable_whitelist: false,
+    }
+}
+
+benchmarks_instance! {
+    _ { }
+
+    add_faucet {
+        let faucet = funded_account::<T, I>("faucet", 0);
+        let settings = default_settings::<T, I>();
+        let call = Call::<T, I>::add_faucet(faucet.clone(), settings);
+        let origin = T::ManagerOrigin::successful_origin();
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        assert!(SettingsByFaucet::<T, I>::contains_key(&faucet));
+    }
+
+    update_faucet {
+        let faucet = funded_account::<T, I>("faucet", 0);
+        Module::<T, I>::add_faucet(T::ManagerOrigin::successful_origin(), faucet.clone(), default_settings::<T, I>())?;
+        let update = FaucetSettingsUpdate {
+            period: None,
+            period_limit: Some(2_000u32.into()),
+            drop_limit: None,
+            vesting: None,
+            allow_partial_drip: None,
+            enable_whitelist: None,
+        };
+        let call = Call::<T, I>::update_faucet(faucet.clone(), update);
+        let origin = T::ManagerOrigin::successful_origin();
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        let settings = SettingsByFaucet::<T, I>::get(&faucet).unwrap();
+        assert_eq!(settings.period_limit, 2_000u32.into());
+    }
+
+    remove_faucets {
+        let f in 1 .. 100;
+
+        let mut faucets = Vec::new();
+        for i in 0 .. f {
+            let faucet = funded_account::<T, I>("faucet", i);
+            Module::<T, I>::add_faucet(T::ManagerOrigin::successful_origin(), faucet.clone(), default_settings::<T, I>())?;
+            faucets.push(faucet);
+        }
+        let call = Call::<T, I>::remove_faucets(faucets.clone());
+        let origin = T::ManagerOrigin::successful_origin();
+    }: { call.dispatch_bypass_filter(origin)? }
+    verify {
+        for faucet in faucets {
+            assert!(!SettingsByFaucet::<T, I>::contains_key(&faucet));
+        }
+    }
+
+    // A drip to a recipient that has never been dripped before (new `Drop`).
+    drip_new {
+        let faucet = funded_account::<T, I>("faucet", 0);
+        Module::<T, I>::add_faucet(T::ManagerOrigin::successful_origin(), faucet.clone(), default_settings::<T, I>())?;
+        let recipient = funded_account::<T, I>("recipient", 0);
+        let amount: BalanceOf<T, I> = 100u32.into();
+    }: drip(RawOrigin::Signed(faucet.clone()), amount, recipient.clone())
+    verify {
+        assert!(DropIdByRecipient::<T, I>::contains_key(&recipient));
+    }
+
+    // A drip to a recipient whose period has elapsed, exercising the reset path.
+    drip_existing {
+        let faucet = funded_account::<T, I>("faucet", 0);
+        Module::<T, I>::add_faucet(T::ManagerOrigin::successful_origin(), faucet.clone(), default_settings::<T, I>())?;
+        let recipient = funded_account::<T, I>("recipient", 0);
+        let amount: BalanceOf<T, I> = 100u32.into();
+        Module::<T, I>::drip(RawOrigin::Signed(faucet.clone()).into(), amount, recipient.clone())?;
+        // Advance past the faucet period so the next drip resets the counters.
+        frame_system::Module::<T>::set_block_number(200u32.into());
+    }: drip(RawOrigin::Signed(faucet.clone()), amount, recipient.clone())
+    verify {
+        let drop_id = DropIdByRecipient::<T, I>::get(&recipient).unwrap();
+        assert_eq!(DropById::<T, I>::get(drop_id).unwrap().total_dropped, amount);
+    }
+}
@@ -2,8 +2,16 @@
 //!
 //! The Faucet module allows a root key (sudo) to add accounts (faucets) that are eligible
 //! to drip free tokens to other accounts (recipients).
-
-// TODO refactor sudo to generic account + add 'created' to FaucetSettings so we can check owner
+//!
+//! Faucet administration is gated behind a configurable [`ManagerOrigin`](Trait::ManagerOrigin)
+//! so it can be wired to governance, a collective or a multisig rather than the sudo key, and
+//! balances drained from removed faucets are returned to a configurable
+//! [`TreasuryAccount`](Trait::TreasuryAccount).
+//!
+//! The pallet is instantiable: a runtime can mount several independent faucet programs
+//! (e.g. onboarding grants, testnet drips, a campaign faucet) via separate instances,
+//! each with its own settings, drop counters and events. The default `()` instance keeps
+//! the original single-instance behavior.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -11,12 +19,15 @@ use codec::{Decode, Encode};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, dispatch::{DispatchError, DispatchResult},
     ensure,
-    traits::{Currency, ExistenceRequirement, Get},
+    traits::{
+        Currency, EnsureOrigin, ExistenceRequirement, Get, Instance, LockIdentifier,
+        LockableCurrency, WithdrawReasons,
+    },
     weights::Pays,
 };
-use frame_system::{self as system, ensure_root, ensure_signed};
-use pallet_sudo::Module as SudoModule;
+use frame_system::{self as system, ensure_signed};
 use sp_runtime::RuntimeDebug;
+use sp_runtime::SaturatedConversion;
 use sp_runtime::traits::{Saturating, Zero};
 use sp_std::{
     collections::btree_set::BTreeSet,
@@ -30,13 +41,32 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+
+pub use weights::WeightInfo;
+
 type DropId = u64;
 
+/// Lock used to hold dripped tokens until they graded-unlock under a [`VestingSchedule`].
+const FAUCET_LOCK_ID: LockIdentifier = *b"faucetdr";
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
-pub struct Drop<T: Trait> {
+pub struct Drop<T: Trait<I>, I: Instance = DefaultInstance> {
     id: DropId,
     last_drop_at: T::BlockNumber,
-    total_dropped: BalanceOf<T>,
+    total_dropped: BalanceOf<T, I>,
+}
+
+/// Turns a faucet into a "vesting drip" faucet: instead of handing out spendable tokens,
+/// every drip is locked on the recipient and unlocks `per_period` worth every `period` blocks
+/// over `period_count` periods. `per_period` is derived from the dripped amount at drip time.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct VestingConfig<BlockNumber> {
+    period: BlockNumber,
+    period_count: u32,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
@@ -44,6 +74,13 @@ pub struct FaucetSettings<BlockNumber, Balance> {
     period: Option<BlockNumber>,
     period_limit: Balance,
     drop_limit: Balance,
+    /// When set, dripped tokens are delivered under a graded unlock schedule.
+    vesting: Option<VestingConfig<BlockNumber>>,
+    /// When `true`, a drip that exceeds `drop_limit` or the remaining period allowance is
+    /// capped to the maximum still-allowed amount instead of being rejected.
+    allow_partial_drip: bool,
+    /// When `true`, the faucet only drips to recipients present in its whitelist.
+    enable_whitelist: bool,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
@@ -51,25 +88,50 @@ pub struct FaucetSettingsUpdate<BlockNumber, Balance> {
     period: Option<Option<BlockNumber>>,
     period_limit: Option<Balance>,
     drop_limit: Option<Balance>,
+    vesting: Option<Option<VestingConfig<BlockNumber>>>,
+    allow_partial_drip: Option<bool>,
+    enable_whitelist: Option<bool>,
+}
+
+/// A single graded unlock schedule of a vesting drip, stored per recipient.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct VestingSchedule<BlockNumber, Balance> {
+    start: BlockNumber,
+    period: BlockNumber,
+    per_period: Balance,
+    period_count: u32,
+    /// The integer-division remainder of the granted amount, front-loaded into the first period so
+    /// the whole drip is locked and nothing leaks out unvested.
+    first_period_extra: Balance,
 }
 
-type BalanceOf<T> = <<T as Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
+type BalanceOf<T, I> =
+    <<T as Trait<I>>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
 
 /// The pallet's configuration trait.
-pub trait Trait: system::Trait + pallet_sudo::Trait {
+pub trait Trait<I: Instance = DefaultInstance>: system::Trait {
     /// The overarching event type.
-    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+    type Event: From<Event<Self, I>> + Into<<Self as system::Trait>::Event>;
 
-    type Currency: Currency<Self::AccountId>;
+    type Currency: LockableCurrency<Self::AccountId>;
+
+    /// The origin that is allowed to add, update and remove faucets (e.g. governance).
+    type ManagerOrigin: EnsureOrigin<Self::Origin>;
+
+    /// The account that receives balances drained from removed faucets.
+    type TreasuryAccount: Get<Self::AccountId>;
+
+    /// Weight information for the extrinsics in this pallet.
+    type WeightInfo: WeightInfo;
 }
 
 decl_storage! {
-	trait Store for Module<T: Trait> as FaucetModule {
+	trait Store for Module<T: Trait<I>, I: Instance = DefaultInstance> as FaucetModule {
 		pub NextDropId get(fn next_drop_id): DropId = 1;
 
 		pub DropById get(fn drop_by_id):
 			map hasher(twox_64_concat) DropId
-			=> Option<Drop<T>>;
+			=> Option<Drop<T, I>>;
 
 		pub DropIdByRecipient get(fn drop_id_by_recipient):
 			map hasher(twox_64_concat) T::AccountId
@@ -77,19 +139,31 @@ decl_storage! {
 
 		pub SettingsByFaucet get(fn settings_by_faucet):
 			map hasher(twox_64_concat) T::AccountId
-			=> Option<FaucetSettings<T::BlockNumber, BalanceOf<T>>>;
+			=> Option<FaucetSettings<T::BlockNumber, BalanceOf<T, I>>>;
 
 	    pub TotalFaucetDropsByAccount get(fn total_faucet_drops_by_account): double_map
 	        hasher(twox_64_concat) T::AccountId,    // Faucet account
 	        hasher(twox_64_concat) T::AccountId     // User account
-	        => BalanceOf<T>;
+	        => BalanceOf<T, I>;
+
+		/// Active vesting schedules that currently lock tokens on a recipient.
+		pub VestingSchedulesByRecipient get(fn vesting_schedules_by_recipient):
+			map hasher(twox_64_concat) T::AccountId
+			=> Vec<VestingSchedule<T::BlockNumber, BalanceOf<T, I>>>;
+
+		/// Whether a recipient is whitelisted on a given faucet (only consulted when the
+		/// faucet has `enable_whitelist` set).
+		pub WhitelistedRecipients get(fn is_whitelisted_recipient): double_map
+			hasher(twox_64_concat) T::AccountId,    // Faucet account
+			hasher(twox_64_concat) T::AccountId     // Recipient account
+			=> bool;
 	}
 }
 
 decl_event!(
-	pub enum Event<T> where
+	pub enum Event<T, I: Instance = DefaultInstance> where
 		AccountId = <T as system::Trait>::AccountId,
-		Balance = BalanceOf<T>
+		Balance = BalanceOf<T, I>
 	{
 		FaucetAdded(AccountId),
 		FaucetUpdated(AccountId),
@@ -99,12 +173,32 @@ decl_event!(
 			AccountId, // recipient
 			Balance // amount dropped
 		),
+		/// A drip was capped to the maximum allowed amount: (faucet, recipient, granted, requested).
+		DroppedPartial(
+			AccountId, // faucet
+			AccountId, // recipient
+			Balance, // amount granted
+			Balance // amount requested
+		),
+		/// A recipient claimed their vested tokens; carries the balance still locked afterwards.
+		VestingClaimed(
+			AccountId, // recipient
+			Balance // amount still locked
+		),
+		RecipientsAddedToWhitelist(
+			AccountId, // faucet
+			Vec<AccountId> // recipients
+		),
+		RecipientsRemovedFromWhitelist(
+			AccountId, // faucet
+			Vec<AccountId> // recipients
+		),
 	}
 );
 
 // The pallet's errors
 decl_error! {
-	pub enum Error for Module<T: Trait> {
+	pub enum Error for Module<T: Trait<I>, I: Instance> {
 		FaucetNotFound,
 		FaucetAlreadyAdded,
 		FaucetLimitReached,
@@ -113,57 +207,66 @@ decl_error! {
 		NothingToUpdate,
 		ZeroAmount,
 		DropAmountLimit,
+		/// The recipient has no vesting schedule with tokens to claim.
+		NothingToClaim,
+		/// No recipients were provided to the whitelist dispatchable.
+		NoRecipientsProvided,
+		/// The recipient is not whitelisted on a faucet that requires a whitelist.
+		RecipientNotAllowed,
 	}
 }
 
 // The pallet's dispatchable functions.
 decl_module! {
     /// The module declaration.
-    pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+    pub struct Module<T: Trait<I>, I: Instance = DefaultInstance> for enum Call where origin: T::Origin {
         // Initializing errors
-        type Error = Error<T>;
+        type Error = Error<T, I>;
 
         // Initializing events
         fn deposit_event() = default;
 
-        #[weight = T::DbWeight::get().reads_writes(1, 1) + 50_000]
+        #[weight = T::WeightInfo::add_faucet()]
         pub fn add_faucet(
             origin,
             faucet: T::AccountId,
-            settings: FaucetSettings<T::BlockNumber, BalanceOf<T>>
+            settings: FaucetSettings<T::BlockNumber, BalanceOf<T, I>>
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::ManagerOrigin::ensure_origin(origin)?;
 
             ensure!(
                 Self::require_faucet_settings(&faucet).is_err(),
-                Error::<T>::FaucetAlreadyAdded
+                Error::<T, I>::FaucetAlreadyAdded
             );
 
             ensure!(
                 T::Currency::free_balance(&faucet) >= T::Currency::minimum_balance(),
-                Error::<T>::NoFreeBalanceOnAccount
+                Error::<T, I>::NoFreeBalanceOnAccount
             );
 
-            SettingsByFaucet::<T>::insert(faucet.clone(), settings);
+            SettingsByFaucet::<T, I>::insert(faucet.clone(), settings);
 
             Self::deposit_event(RawEvent::FaucetAdded(faucet));
             Ok(())
         }
 
-        #[weight = T::DbWeight::get().reads_writes(1, 1) + 20_000]
+        #[weight = T::WeightInfo::update_faucet()]
         pub fn update_faucet(
             origin,
             faucet: T::AccountId,
-            update: FaucetSettingsUpdate<T::BlockNumber, BalanceOf<T>>
+            update: FaucetSettingsUpdate<T::BlockNumber, BalanceOf<T, I>>
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::ManagerOrigin::ensure_origin(origin)?;
 
             let has_updates =
                 update.period.is_some() ||
                 update.period_limit.is_some() ||
-                update.drop_limit.is_some();
+                update.drop_limit.is_some() ||
+                update.vesting.is_some() ||
+                update.allow_partial_drip.is_some() ||
+                update.enable_whitelist.is_some();
 
-            ensure!(has_updates, Error::<T>::NothingToUpdate);
+            ensure!(has_updates, Error::<T, I>::NothingToUpdate);
 
             let mut settings = Self::require_faucet_settings(&faucet)?;
 
@@ -191,35 +294,56 @@ decl_module! {
                 }
             }
 
+            if let Some(vesting) = update.vesting {
+                if vesting != settings.vesting {
+                    settings.vesting = vesting;
+                    should_update = true;
+                }
+            }
+
+            if let Some(allow_partial_drip) = update.allow_partial_drip {
+                if allow_partial_drip != settings.allow_partial_drip {
+                    settings.allow_partial_drip = allow_partial_drip;
+                    should_update = true;
+                }
+            }
+
+            if let Some(enable_whitelist) = update.enable_whitelist {
+                if enable_whitelist != settings.enable_whitelist {
+                    settings.enable_whitelist = enable_whitelist;
+                    should_update = true;
+                }
+            }
+
             if should_update {
-                SettingsByFaucet::<T>::insert(faucet.clone(), settings);
+                SettingsByFaucet::<T, I>::insert(faucet.clone(), settings);
                 Self::deposit_event(RawEvent::FaucetUpdated(faucet));
                 return Ok(());
             }
-            Err(Error::<T>::NothingToUpdate.into())
+            Err(Error::<T, I>::NothingToUpdate.into())
         }
 
-        #[weight = T::DbWeight::get().reads_writes(0, 1) + 10_000 + 5_000 * faucets.len() as u64]
+        #[weight = T::WeightInfo::remove_faucets(faucets.len() as u32)]
         pub fn remove_faucets(
             origin,
             faucets: Vec<T::AccountId>
         ) -> DispatchResult {
-            ensure_root(origin)?;
-            let root_key = SudoModule::<T>::key();
+            T::ManagerOrigin::ensure_origin(origin)?;
+            let treasury = T::TreasuryAccount::get();
 
-            ensure!(faucets.len() != Zero::zero(), Error::<T>::NoFaucetsProvided);
+            ensure!(faucets.len() != Zero::zero(), Error::<T, I>::NoFaucetsProvided);
 
             let unique_faucets = BTreeSet::from_iter(faucets.iter());
             for faucet in unique_faucets.iter() {
                 if Self::require_faucet_settings(faucet).is_ok() {
                     T::Currency::transfer(
                         faucet,
-                        &root_key,
+                        &treasury,
                         T::Currency::free_balance(faucet),
                         ExistenceRequirement::AllowDeath
                     )?;
 
-                    SettingsByFaucet::<T>::remove(faucet);
+                    SettingsByFaucet::<T, I>::remove(faucet);
                 }
             }
 
@@ -227,21 +351,27 @@ decl_module! {
             Ok(())
         }
 
-        #[weight = (
-            T::DbWeight::get().reads_writes(6, 4) + 50_000,
-            Pays::No
-        )]
+        #[weight = (T::WeightInfo::drip(), Pays::No)]
         pub fn drip(
             origin, // faucet account
-            amount: BalanceOf<T>,
+            amount: BalanceOf<T, I>,
             recipient: T::AccountId
         ) -> DispatchResult {
             let faucet = ensure_signed(origin)?;
 
-            ensure!(amount > Zero::zero(), Error::<T>::ZeroAmount);
+            ensure!(amount > Zero::zero(), Error::<T, I>::ZeroAmount);
 
             let settings = Self::require_faucet_settings(&faucet)?;
-            ensure!(amount <= settings.drop_limit, Error::<T>::DropAmountLimit);
+
+            ensure!(
+                !settings.enable_whitelist || Self::is_whitelisted_recipient(&faucet, &recipient),
+                Error::<T, I>::RecipientNotAllowed
+            );
+
+            ensure!(
+                settings.allow_partial_drip || amount <= settings.drop_limit,
+                Error::<T, I>::DropAmountLimit
+            );
 
             let maybe_drop = Self::drop_id_by_recipient(&recipient).and_then(Self::drop_by_id);
 
@@ -249,55 +379,240 @@ decl_module! {
             let mut drop = maybe_drop.unwrap_or_else(|| {
                 is_new_drop = true;
                 let drop_id = Self::next_drop_id();
-                Drop::<T>::new(drop_id)
+                Drop::<T, I>::new(drop_id)
             });
 
             if !is_new_drop {
-                let current_block = <system::Module<T>>::block_number();
-                let last_period_update = current_block.saturating_sub(settings.period.unwrap_or_else(Zero::zero));
-
-                if last_period_update >= drop.last_drop_at {
-                    drop.last_drop_at = current_block;
-                    if settings.period.is_some() {
-                        drop.total_dropped = Zero::zero();
-                    }
-                }
+                Self::apply_period_reset(&settings, &mut drop);
             }
 
             let amount_allowed = settings.period_limit.saturating_sub(drop.total_dropped);
-            ensure!(amount_allowed >= amount, Error::<T>::FaucetLimitReached);
+
+            // In partial-fulfillment mode we hand out as much as the caps still allow; otherwise
+            // a request over any cap is rejected outright.
+            let granted = if settings.allow_partial_drip {
+                amount.min(settings.drop_limit).min(amount_allowed)
+            } else {
+                ensure!(amount_allowed >= amount, Error::<T, I>::FaucetLimitReached);
+                amount
+            };
+
+            ensure!(granted > Zero::zero(), Error::<T, I>::FaucetLimitReached);
 
             T::Currency::transfer(
                 &faucet,
                 &recipient,
-                amount,
+                granted,
                 ExistenceRequirement::KeepAlive
             )?;
 
-            drop.total_dropped = drop.total_dropped.saturating_add(amount);
+            drop.total_dropped = drop.total_dropped.saturating_add(granted);
+
+            // When the faucet vests, the recipient receives the tokens but they stay locked
+            // and unlock gradually under a new schedule.
+            if let Some(vesting) = settings.vesting.clone() {
+                if vesting.period_count > 0 && !vesting.period.is_zero() {
+                    let period_count: BalanceOf<T, I> = vesting.period_count.into();
+                    let per_period = granted / period_count;
+                    // Lock the full `granted`: anything lost to integer division unlocks with the
+                    // first period instead of being handed out immediately spendable. This also
+                    // covers `granted < period_count`, where `per_period` is zero.
+                    let first_period_extra = granted.saturating_sub(per_period.saturating_mul(period_count));
+                    let schedule = VestingSchedule {
+                        start: <system::Module<T>>::block_number(),
+                        period: vesting.period,
+                        per_period,
+                        period_count: vesting.period_count,
+                        first_period_extra,
+                    };
+                    VestingSchedulesByRecipient::<T, I>::mutate(&recipient, |schedules| schedules.push(schedule));
+                    Self::update_recipient_lock(&recipient);
+                }
+            }
 
-            TotalFaucetDropsByAccount::<T>::mutate(&recipient, &faucet, |total| *total = total.saturating_add(amount));
-            DropIdByRecipient::<T>::insert(&recipient, drop.id);
-            DropById::<T>::insert(drop.id, drop);
+            TotalFaucetDropsByAccount::<T, I>::mutate(&recipient, &faucet, |total| *total = total.saturating_add(granted));
+            DropIdByRecipient::<T, I>::insert(&recipient, drop.id);
+            DropById::<T, I>::insert(drop.id, drop);
             if is_new_drop {
-                NextDropId::mutate(|x| *x += 1);
+                NextDropId::<I>::mutate(|x| *x += 1);
             }
 
-            Self::deposit_event(RawEvent::Dropped(faucet, recipient, amount));
+            if granted < amount {
+                Self::deposit_event(RawEvent::DroppedPartial(faucet, recipient, granted, amount));
+            } else {
+                Self::deposit_event(RawEvent::Dropped(faucet, recipient, granted));
+            }
+            Ok(())
+        }
+
+        #[weight = T::WeightInfo::claim()]
+        pub fn claim(origin) -> DispatchResult {
+            let recipient = ensure_signed(origin)?;
+
+            ensure!(
+                !Self::vesting_schedules_by_recipient(&recipient).is_empty(),
+                Error::<T, I>::NothingToClaim
+            );
+
+            let still_locked = Self::update_recipient_lock(&recipient);
+
+            Self::deposit_event(RawEvent::VestingClaimed(recipient, still_locked));
+            Ok(())
+        }
+
+        #[weight = T::WeightInfo::add_recipients_to_whitelist(recipients.len() as u32)]
+        pub fn add_recipients_to_whitelist(
+            origin,
+            faucet: T::AccountId,
+            recipients: Vec<T::AccountId>
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(!recipients.is_empty(), Error::<T, I>::NoRecipientsProvided);
+
+            for recipient in BTreeSet::from_iter(recipients.iter()) {
+                WhitelistedRecipients::<T, I>::insert(&faucet, recipient, true);
+            }
+
+            Self::deposit_event(RawEvent::RecipientsAddedToWhitelist(faucet, recipients));
+            Ok(())
+        }
+
+        #[weight = T::WeightInfo::remove_recipients_from_whitelist(recipients.len() as u32)]
+        pub fn remove_recipients_from_whitelist(
+            origin,
+            faucet: T::AccountId,
+            recipients: Vec<T::AccountId>
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            ensure!(!recipients.is_empty(), Error::<T, I>::NoRecipientsProvided);
+
+            for recipient in BTreeSet::from_iter(recipients.iter()) {
+                WhitelistedRecipients::<T, I>::remove(&faucet, recipient);
+            }
+
+            Self::deposit_event(RawEvent::RecipientsRemovedFromWhitelist(faucet, recipients));
             Ok(())
         }
     }
 }
 
-impl<T: Trait> Module<T> {
+impl<T: Trait<I>, I: Instance> Module<T, I> {
     pub fn require_faucet_settings(
         faucet: &T::AccountId
-    ) -> Result<FaucetSettings<T::BlockNumber, BalanceOf<T>>, DispatchError> {
-        Ok(Self::settings_by_faucet(faucet).ok_or(Error::<T>::FaucetNotFound)?)
+    ) -> Result<FaucetSettings<T::BlockNumber, BalanceOf<T, I>>, DispatchError> {
+        Ok(Self::settings_by_faucet(faucet).ok_or(Error::<T, I>::FaucetNotFound)?)
+    }
+
+    /// If the recipient's drip period has elapsed, advances `last_drop_at` to the current block
+    /// and (for periodic faucets) resets `total_dropped`. Shared by `drip` and the runtime API
+    /// so eligibility can be computed without simulating an extrinsic.
+    fn apply_period_reset(
+        settings: &FaucetSettings<T::BlockNumber, BalanceOf<T, I>>,
+        drop: &mut Drop<T, I>,
+    ) {
+        let current_block = <system::Module<T>>::block_number();
+        let last_period_update = current_block.saturating_sub(settings.period.unwrap_or_else(Zero::zero));
+
+        if last_period_update >= drop.last_drop_at {
+            drop.last_drop_at = current_block;
+            if settings.period.is_some() {
+                drop.total_dropped = Zero::zero();
+            }
+        }
+    }
+
+    /// The balance the recipient may still be dripped from this faucet in the current period,
+    /// accounting for a period that has already elapsed and thus reset to the full limit.
+    pub fn get_remaining_allowance(
+        faucet: T::AccountId,
+        recipient: T::AccountId,
+    ) -> BalanceOf<T, I> {
+        let settings = match Self::settings_by_faucet(&faucet) {
+            Some(settings) => settings,
+            None => return Zero::zero(),
+        };
+
+        match Self::drop_id_by_recipient(&recipient).and_then(Self::drop_by_id) {
+            None => settings.period_limit,
+            Some(mut drop) => {
+                Self::apply_period_reset(&settings, &mut drop);
+                settings.period_limit.saturating_sub(drop.total_dropped)
+            }
+        }
+    }
+
+    /// The block at which the recipient's allowance next resets to the full period limit, or
+    /// `None` for a faucet with no period or a recipient that has never been dripped.
+    pub fn get_next_reset_block(
+        faucet: T::AccountId,
+        recipient: T::AccountId,
+    ) -> Option<T::BlockNumber> {
+        let period = Self::settings_by_faucet(&faucet)?.period?;
+        let drop = Self::drop_id_by_recipient(&recipient).and_then(Self::drop_by_id)?;
+        Some(drop.last_drop_at.saturating_add(period))
+    }
+
+    pub fn get_faucet_settings(
+        faucet: T::AccountId,
+    ) -> Option<FaucetSettings<T::BlockNumber, BalanceOf<T, I>>> {
+        Self::settings_by_faucet(&faucet)
+    }
+
+    /// Recomputes the balance still locked by a schedule at the current block. The whole granted
+    /// amount is `per_period * period_count + first_period_extra`; the remainder unlocks together
+    /// with the first period, so once any period has elapsed the unlocked amount includes it.
+    fn locked_of_schedule(schedule: &VestingSchedule<T::BlockNumber, BalanceOf<T, I>>) -> BalanceOf<T, I> {
+        let total = schedule.per_period
+            .saturating_mul(schedule.period_count.into())
+            .saturating_add(schedule.first_period_extra);
+        if schedule.period.is_zero() {
+            return Zero::zero();
+        }
+
+        let now = <system::Module<T>>::block_number();
+        let elapsed = now.saturating_sub(schedule.start);
+        let periods = (elapsed / schedule.period)
+            .saturated_into::<u32>()
+            .min(schedule.period_count);
+        let unlocked = if periods == 0 {
+            Zero::zero()
+        } else {
+            schedule.per_period
+                .saturating_mul(periods.into())
+                .saturating_add(schedule.first_period_extra)
+        };
+
+        total.saturating_sub(unlocked)
+    }
+
+    /// Drops fully-vested schedules, then sets (or removes) the recipient's faucet lock to the
+    /// sum of what is still locked. Returns the remaining locked balance.
+    fn update_recipient_lock(recipient: &T::AccountId) -> BalanceOf<T, I> {
+        let mut still_locked: BalanceOf<T, I> = Zero::zero();
+        let schedules: Vec<_> = Self::vesting_schedules_by_recipient(recipient)
+            .into_iter()
+            .filter(|schedule| {
+                let locked = Self::locked_of_schedule(schedule);
+                still_locked = still_locked.saturating_add(locked);
+                locked > Zero::zero()
+            })
+            .collect();
+
+        if still_locked.is_zero() {
+            T::Currency::remove_lock(FAUCET_LOCK_ID, recipient);
+            VestingSchedulesByRecipient::<T, I>::remove(recipient);
+        } else {
+            T::Currency::set_lock(FAUCET_LOCK_ID, recipient, still_locked, WithdrawReasons::all());
+            VestingSchedulesByRecipient::<T, I>::insert(recipient, schedules);
+        }
+
+        still_locked
     }
 }
 
-impl<T: Trait> Drop<T> {
+impl<T: Trait<I>, I: Instance> Drop<T, I> {
     pub fn new(id: DropId) -> Self {
         Self {
             id,
@@ -305,4 +620,4 @@ impl<T: Trait> Drop<T> {
             total_dropped: Zero::zero(),
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,103 @@
+//! Weights for pallet_faucets
+//!
+//! `benchmarking.rs` benchmarks `add_faucet`, `update_faucet`, `remove_faucets` and `drip`
+//! (new-drop and period-reset paths); `claim` and the whitelist dispatchables have no benchmark.
+//! The `()` implementation is a sensible default for runtimes that do not run benchmarks.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_faucets.
+pub trait WeightInfo {
+    fn add_faucet() -> Weight;
+    fn update_faucet() -> Weight;
+    fn remove_faucets(f: u32) -> Weight;
+    fn drip() -> Weight;
+    fn claim() -> Weight;
+    fn add_recipients_to_whitelist(r: u32) -> Weight;
+    fn remove_recipients_from_whitelist(r: u32) -> Weight;
+}
+
+/// Weights for pallet_faucets using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Trait> WeightInfo for SubstrateWeight<T> {
+    fn add_faucet() -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn update_faucet() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(1 as Weight))
+            .saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn remove_faucets(f: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((15_000_000 as Weight).saturating_mul(f as Weight))
+            .saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(f as Weight)))
+            .saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(f as Weight)))
+    }
+    fn drip() -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(6 as Weight))
+            .saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn claim() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(T::DbWeight::get().reads(2 as Weight))
+            .saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn add_recipients_to_whitelist(r: u32) -> Weight {
+        (10_000_000 as Weight)
+            .saturating_add((5_000_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+    }
+    fn remove_recipients_from_whitelist(r: u32) -> Weight {
+        (10_000_000 as Weight)
+            .saturating_add((5_000_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    fn add_faucet() -> Weight {
+        (50_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn update_faucet() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(1 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
+    fn remove_faucets(f: u32) -> Weight {
+        (30_000_000 as Weight)
+            .saturating_add((15_000_000 as Weight).saturating_mul(f as Weight))
+            .saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(f as Weight)))
+            .saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(f as Weight)))
+    }
+    fn drip() -> Weight {
+        (80_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(6 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(4 as Weight))
+    }
+    fn claim() -> Weight {
+        (45_000_000 as Weight)
+            .saturating_add(RocksDbWeight::get().reads(2 as Weight))
+            .saturating_add(RocksDbWeight::get().writes(2 as Weight))
+    }
+    fn add_recipients_to_whitelist(r: u32) -> Weight {
+        (10_000_000 as Weight)
+            .saturating_add((5_000_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+    }
+    fn remove_recipients_from_whitelist(r: u32) -> Weight {
+        (10_000_000 as Weight)
+            .saturating_add((5_000_000 as Weight).saturating_mul(r as Weight))
+            .saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+    }
+}
@@ -4,16 +4,23 @@
 use codec::{Decode, Encode};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage,
-    dispatch::DispatchResult, ensure, traits::Get,
+    dispatch::DispatchResult, ensure,
+    traits::{Currency, EnsureOrigin, ExistenceRequirement, Get},
+    weights::Weight,
 };
-use sp_runtime::RuntimeDebug;
+use frame_system::ensure_signed;
+use sp_runtime::{Permill, RuntimeDebug, SaturatedConversion, traits::{Saturating, Zero}};
+use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
 
 use pallet_posts::{Module as Posts, Post, PostById, PostExtension, PostId};
 use pallet_profiles::{Module as Profiles, SocialAccountById};
 use pallet_reactions::ReactionKind;
 use pallet_spaces::{Module as Spaces, SpaceById};
-use pallet_utils::log_2;
+use pallet_utils::{log_2, SpaceId};
+
+type BalanceOf<T> =
+    <<T as Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
 
 // mod tests;
 
@@ -28,6 +35,10 @@ pub enum ScoringAction {
     ShareComment,
     FollowSpace,
     FollowAccount,
+    TipPost,
+    TipComment,
+    /// Reputation lost to the idle-decay sweep rather than to a social action.
+    Decay,
 }
 
 impl Default for ScoringAction {
@@ -36,6 +47,107 @@ impl Default for ScoringAction {
     }
 }
 
+/// Lets the scoring pallet ask whether a post or account has been hidden by moderators so that
+/// blocked content and accounts stop accruing score and reputation.
+pub trait ModerationProvider<AccountId> {
+    fn is_post_blocked(post_id: PostId, space_id: SpaceId) -> bool;
+    fn is_account_blocked_in_space(account: &AccountId, space_id: SpaceId) -> bool;
+}
+
+/// A no-op provider for runtimes without moderation: nothing is ever blocked.
+impl<AccountId> ModerationProvider<AccountId> for () {
+    fn is_post_blocked(_post_id: PostId, _space_id: SpaceId) -> bool {
+        false
+    }
+    fn is_account_blocked_in_space(_account: &AccountId, _space_id: SpaceId) -> bool {
+        false
+    }
+}
+
+/// Maps an account's reputation into an "influence" multiplier that is later multiplied by the
+/// action weight in `score_diff_for_action`. Implementations decide the anti-whale behavior.
+///
+/// `score_diff_for_action` clamps the influence to `i16::MAX` and then multiplies by the weight in
+/// `i32`, saturating the product back into `i16` (see `saturating_score`). A curve may therefore
+/// return any `u16` without wrapping a score negative; the trade-off is that an influence/weight
+/// pair whose product exceeds `i16::MAX` saturates rather than scaling linearly, so a runtime that
+/// wants a full dynamic range should still size its curve ceiling and weights together.
+pub trait ReputationCurve {
+    fn influence(reputation: u32) -> u16;
+}
+
+/// The original `log_2`-based curve: reputation maps into roughly `1..=32`, so `influence * weight`
+/// stays well within `i16`. Preserves the historic scoring behavior.
+pub struct Log2Curve;
+impl ReputationCurve for Log2Curve {
+    fn influence(reputation: u32) -> u16 {
+        log_2(reputation).map_or(1, |r| {
+            let d = (reputation as u64 - (2 as u64).pow(r)) * 100
+                / (2 as u64).pow(r);
+
+            (((r + 1) * 100 + d as u32) / 100) as u16
+        })
+    }
+}
+
+/// A gentler curve using integer square root, e.g. reputation `10_000` maps to `100`. Runtimes
+/// using it must keep weights small enough that `sqrt(reputation) * weight` fits in `i16`.
+pub struct SqrtCurve;
+impl ReputationCurve for SqrtCurve {
+    fn influence(reputation: u32) -> u16 {
+        // Integer square root via Newton's method.
+        if reputation == 0 {
+            return 1;
+        }
+        let mut x = reputation;
+        // `(x + 1) / 2` without the `+ 1` overflowing for `u32::MAX`.
+        let mut y = x / 2 + x % 2;
+        while y < x {
+            x = y;
+            y = (x + reputation / x) / 2;
+        }
+        x.max(1) as u16
+    }
+}
+
+/// A curve that grows linearly with `slope` until it hits `cap`. Both are supplied via `Get`, so a
+/// runtime can pick the ceiling that keeps `cap * weight` within `i16`.
+pub struct CappedLinearCurve<Slope, Cap>(PhantomData<(Slope, Cap)>);
+impl<Slope: Get<u16>, Cap: Get<u16>> ReputationCurve for CappedLinearCurve<Slope, Cap> {
+    fn influence(reputation: u32) -> u16 {
+        let scaled = reputation.saturating_mul(Slope::get() as u32);
+        scaled.min(Cap::get() as u32).max(1) as u16
+    }
+}
+
+/// Clamps a curve's influence to `i16::MAX` so casting it to `i16` in `score_diff_for_action`
+/// can never wrap negative and flip the sign of a score (a real hazard for curves whose `Cap`
+/// exceeds `32767`, e.g. `CappedLinearCurve` with `Cap = 50_000`).
+pub fn clamp_influence(influence: u16) -> i16 {
+    influence.min(i16::max_value() as u16) as i16
+}
+
+/// Multiplies a clamped influence by an action weight in `i32` and saturates back into `i16`, so
+/// a large influence (up to `i16::MAX` after clamping) times a weight can never overflow `i16` and
+/// flip a score's sign — a real hazard for the `SqrtCurve`/`CappedLinearCurve` ceilings.
+pub fn saturating_score(influence: i16, weight: i16) -> i16 {
+    (influence as i32 * weight as i32)
+        .max(i16::min_value() as i32)
+        .min(i16::max_value() as i32) as i16
+}
+
+/// A single entry in an account's reputation change history.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct ReputationChangeRecord<T: Trait> {
+    /// The account whose action caused the change.
+    scorer: T::AccountId,
+    action: ScoringAction,
+    diff: i16,
+    reputation_before: u32,
+    reputation_after: u32,
+    block: T::BlockNumber,
+}
+
 /// The pallet's configuration trait.
 pub trait Trait: system::Trait
     + pallet_utils::Trait
@@ -46,6 +158,10 @@ pub trait Trait: system::Trait
     /// The overarching event type.
     type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
 
+    /// The origin that is allowed to adjust scoring action weights at runtime
+    /// (intended to be a council/collective origin).
+    type WeightAdjustmentOrigin: EnsureOrigin<Self::Origin>;
+
     // Weights of the social actions
     type FollowSpaceActionWeight: Get<i16>;
     type FollowAccountActionWeight: Get<i16>;
@@ -58,6 +174,30 @@ pub trait Trait: system::Trait
     type ShareCommentActionWeight: Get<i16>;
     type UpvoteCommentActionWeight: Get<i16>;
     type DownvoteCommentActionWeight: Get<i16>;
+
+    type TipPostActionWeight: Get<i16>;
+    type TipCommentActionWeight: Get<i16>;
+
+    /// Currency used to transfer tips from a tipper to a content author.
+    type Currency: Currency<Self::AccountId>;
+
+    /// Number of blocks an account may stay idle before its reputation starts decaying.
+    type DecayPeriod: Get<Self::BlockNumber>;
+
+    /// Fraction of the current reputation removed on each decay step.
+    type DecayPermill: Get<Permill>;
+
+    /// Maximum number of accounts swept by the decay hook per block (bounds the hook weight).
+    type MaxDecayAccountsPerBlock: Get<u32>;
+
+    /// Maximum number of reputation change records kept per account (ring buffer capacity).
+    type MaxReputationHistoryLen: Get<u32>;
+
+    /// The curve that maps reputation into influence before applying the action weight.
+    type Curve: ReputationCurve;
+
+    /// Hook used to skip scoring for moderated (blocked) content and accounts.
+    type ModerationProvider: ModerationProvider<Self::AccountId>;
 }
 
 decl_error! {
@@ -85,6 +225,12 @@ decl_error! {
         ReputationOverflow,
         /// Out of bounds decreasing a reputation of a social account.
         ReputationUnderflow,
+        /// A tip must transfer a non-zero amount.
+        ZeroTip,
+        /// An account cannot tip its own post.
+        CannotTipOwnPost,
+        /// Cannot reconcile the score of a post when neither it nor its author is blocked.
+        PostNotBlocked,
     }
 }
 
@@ -93,14 +239,40 @@ decl_storage! {
     trait Store for Module<T: Trait> as TemplateModule {
         pub AccountReputationDiffByAccount get(fn account_reputation_diff_by_account): map (T::AccountId, T::AccountId, ScoringAction) => Option<i16>; // TODO shorten name (?refactor)
         pub PostScoreByAccount get(fn post_score_by_account): map (T::AccountId, PostId, ScoringAction) => Option<i16>;
+
+        /// Runtime overrides of the compile-time action weights. When an action has no entry,
+        /// `weight_of_scoring_action` falls back to the corresponding `Config` default.
+        pub ActionWeightOverride get(fn action_weight_override): map ScoringAction => Option<i16>;
+
+        /// Block at which an account's reputation last changed. Used to detect idle accounts.
+        pub LastReputationTouch get(fn last_reputation_touch): map T::AccountId => T::BlockNumber;
+
+        /// Key-based cursor into `SocialAccountById` for the decay sweep: the raw storage key of
+        /// the last account swept. `None` restarts the sweep from the first key. Using the key
+        /// (not a positional offset) keeps the sweep stable across inserts and removals.
+        pub DecayCursor get(fn decay_cursor): Option<Vec<u8>>;
+
+        /// Bounded audit trail of every reputation change affecting an account.
+        pub ReputationHistory get(fn reputation_history): map T::AccountId => Vec<ReputationChangeRecord<T>>;
+
+        /// The set of `(scorer, action)` pairs that have contributed score to a post, so a later
+        /// reconciliation (`revert_blocked_post_score`) can reverse every one of them.
+        pub ScorersByPost get(fn scorers_by_post): map PostId => Vec<(T::AccountId, ScoringAction)>;
     }
 }
 
 decl_event!(
     pub enum Event<T> where
         <T as system::Trait>::AccountId,
+        Balance = BalanceOf<T>,
     {
         AccountReputationChanged(AccountId, ScoringAction, u32),
+        /// An idle account's reputation was reduced by the decay sweep: (account, new reputation).
+        AccountReputationDecayed(AccountId, u32),
+        /// A scoring action weight was overridden at runtime: (action, new weight).
+        ActionWeightSet(ScoringAction, i16),
+        /// A post was tipped: (tipper, post id, amount).
+        PostTipped(AccountId, PostId, Balance),
     }
 );
 
@@ -118,9 +290,115 @@ decl_module! {
         const UpvoteCommentActionWeight: i16 = T::UpvoteCommentActionWeight::get();
         const DownvoteCommentActionWeight: i16 = T::DownvoteCommentActionWeight::get();
         const ShareCommentActionWeight: i16 = T::ShareCommentActionWeight::get();
+        const TipPostActionWeight: i16 = T::TipPostActionWeight::get();
+        const TipCommentActionWeight: i16 = T::TipCommentActionWeight::get();
+
+        /// Reputation decay configuration.
+        const DecayPeriod: T::BlockNumber = T::DecayPeriod::get();
+        const DecayPermill: Permill = T::DecayPermill::get();
+        const MaxDecayAccountsPerBlock: u32 = T::MaxDecayAccountsPerBlock::get();
+        const MaxReputationHistoryLen: u32 = T::MaxReputationHistoryLen::get();
+
+        // Initializing errors
+        type Error = Error<T>;
 
         // Initializing events
         fn deposit_event() = default;
+
+        fn on_initialize(_now: T::BlockNumber) -> Weight {
+            Self::decay_reputation_sweep()
+        }
+
+        /// Override the reputation weight granted by a scoring action. Gated by
+        /// `WeightAdjustmentOrigin` so the reputation economy can be tuned by governance
+        /// without a runtime upgrade.
+        #[weight = 10_000]
+        pub fn set_action_weight(origin, action: ScoringAction, weight: i16) -> DispatchResult {
+            T::WeightAdjustmentOrigin::ensure_origin(origin)?;
+
+            ActionWeightOverride::insert(action, weight);
+
+            Self::deposit_event(RawEvent::ActionWeightSet(action, weight));
+            Ok(())
+        }
+
+        /// Tip a post with an on-chain transfer to its author. Unlike free votes, the reputation
+        /// and score gain scale with the tipped amount (log-damped so large tips give a
+        /// diminishing bonus), tying scoring to real value movement.
+        #[weight = 100_000]
+        pub fn tip_post(origin, post_id: PostId, amount: BalanceOf<T>) -> DispatchResult {
+            let tipper = ensure_signed(origin)?;
+
+            ensure!(!amount.is_zero(), Error::<T>::ZeroTip);
+
+            let mut post = Posts::require_post(post_id)?;
+            let author = post.created.account.clone();
+            ensure!(author != tipper, Error::<T>::CannotTipOwnPost);
+
+            // Blocked content and authors must not accrue score or reputation via tipping either,
+            // otherwise the moderation hole the votes path closes stays open through `tip_post`.
+            if let Some(space_id) = post.space_id {
+                if T::ModerationProvider::is_post_blocked(post.id, space_id)
+                    || T::ModerationProvider::is_account_blocked_in_space(&author, space_id) {
+                    return Ok(());
+                }
+            }
+
+            T::Currency::transfer(&tipper, &author, amount, ExistenceRequirement::KeepAlive)?;
+
+            let action = if post.is_comment() { ScoringAction::TipComment } else { ScoringAction::TipPost };
+
+            let social_account = Profiles::get_or_new_social_account(tipper.clone());
+            <SocialAccountById<T>>::insert(tipper.clone(), social_account.clone());
+
+            // `influence * weight`, then damped by a log of the tipped amount so large tips
+            // grant a diminishing reputation bonus.
+            let base = Self::score_diff_for_action(social_account.reputation, action);
+            let score_diff = base.saturating_mul(Self::tip_value_factor(amount));
+
+            // Only tipping a top-level post lifts the space score: the non-tip comment path
+            // (`change_comment_score`) deliberately leaves space score untouched, so comment tips
+            // must not inflate it either.
+            if action == ScoringAction::TipPost {
+                if let Some(post_space_id) = post.space_id {
+                    let mut space = Spaces::require_space(post_space_id)?;
+                    space.score = space.score.checked_add(score_diff as i32).ok_or(Error::<T>::SpaceScoreOverflow)?;
+                    <SpaceById<T>>::insert(post_space_id, space);
+                }
+            }
+
+            post.score = post.score.checked_add(score_diff as i32).ok_or(Error::<T>::PostScoreOverflow)?;
+            Self::change_social_account_reputation(author, tipper.clone(), score_diff, action)?;
+
+            // Tips accumulate (value was spent), so there is no toggle-off like for votes.
+            <PostScoreByAccount<T>>::mutate((tipper.clone(), post_id, action), |maybe_diff| {
+                *maybe_diff = Some(maybe_diff.unwrap_or(0).saturating_add(score_diff));
+            });
+            Self::remember_post_scorer(post_id, tipper.clone(), action);
+            <PostById<T>>::insert(post_id, post);
+
+            Self::deposit_event(RawEvent::PostTipped(tipper, post_id, amount));
+            Ok(())
+        }
+
+        /// Reconcile the score of a post that moderators have blocked by reversing every scorer's
+        /// accumulated contribution. Permissionless, but only succeeds for a post that is blocked
+        /// or whose author is blocked in its space, so it cannot be used to strip score from live
+        /// content.
+        #[weight = 100_000]
+        pub fn revert_blocked_post_score(origin, post_id: PostId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let mut post = Posts::require_post(post_id)?;
+            let space_id = post.space_id.ok_or(Error::<T>::PostNotBlocked)?;
+            ensure!(
+                T::ModerationProvider::is_post_blocked(post.id, space_id)
+                    || T::ModerationProvider::is_account_blocked_in_space(&post.created.account, space_id),
+                Error::<T>::PostNotBlocked
+            );
+
+            Self::revert_all_scores(&mut post)
+        }
     }
 }
 
@@ -167,6 +445,15 @@ impl<T: Trait> Module<T> {
     ) -> DispatchResult {
         ensure!(!post.is_comment(), Error::<T>::PostIsAComment);
 
+        // Skip scoring for moderated posts or authors so abusers cannot farm reputation
+        // on content that moderators have hidden.
+        if let Some(space_id) = post.space_id {
+            if T::ModerationProvider::is_post_blocked(post.id, space_id)
+                || T::ModerationProvider::is_account_blocked_in_space(&post.created.account, space_id) {
+                return Ok(());
+            }
+        }
+
         let social_account = Profiles::get_or_new_social_account(account.clone());
 
         // TODO inspect: this insert could be redundant if the account already exists.
@@ -189,7 +476,8 @@ impl<T: Trait> Module<T> {
                     post.score = post.score.checked_sub(score_diff as i32).ok_or(Error::<T>::PostScoreUnderflow)?;
                     space.score = space.score.checked_sub(score_diff as i32).ok_or(Error::<T>::SpaceScoreUnderflow)?;
                     Self::change_social_account_reputation(post.created.account.clone(), account.clone(), -reputation_diff, action)?;
-                    <PostScoreByAccount<T>>::remove((account, post_id, action));
+                    <PostScoreByAccount<T>>::remove((account.clone(), post_id, action));
+                    Self::forget_post_scorer(post_id, account.clone(), action);
                 } else {
                     match action {
                         ScoringAction::UpvotePost => {
@@ -208,7 +496,8 @@ impl<T: Trait> Module<T> {
                     post.score = post.score.checked_add(score_diff as i32).ok_or(Error::<T>::PostScoreOverflow)?;
                     space.score = space.score.checked_add(score_diff as i32).ok_or(Error::<T>::SpaceScoreOverflow)?;
                     Self::change_social_account_reputation(post.created.account.clone(), account.clone(), score_diff, action)?;
-                    <PostScoreByAccount<T>>::insert((account, post_id, action), score_diff);
+                    <PostScoreByAccount<T>>::insert((account.clone(), post_id, action), score_diff);
+                    Self::remember_post_scorer(post_id, account.clone(), action);
                 }
 
                 <PostById<T>>::insert(post_id, post.clone());
@@ -226,6 +515,14 @@ impl<T: Trait> Module<T> {
     ) -> DispatchResult {
         ensure!(comment.is_comment(), Error::<T>::PostIsNotAComment);
 
+        // Skip scoring for moderated comments or authors (see `change_post_score`).
+        if let Some(space_id) = comment.space_id {
+            if T::ModerationProvider::is_post_blocked(comment.id, space_id)
+                || T::ModerationProvider::is_account_blocked_in_space(&comment.created.account, space_id) {
+                return Ok(());
+            }
+        }
+
         let social_account = Profiles::get_or_new_social_account(account.clone());
 
         // TODO inspect: this insert could be redundant if the account already exists.
@@ -244,7 +541,8 @@ impl<T: Trait> Module<T> {
 
                 comment.score = comment.score.checked_sub(score_diff as i32).ok_or(Error::<T>::CommentScoreUnderflow)?;
                 Self::change_social_account_reputation(comment.created.account.clone(), account.clone(), -reputation_diff, action)?;
-                <PostScoreByAccount<T>>::remove((account, comment_id, action));
+                <PostScoreByAccount<T>>::remove((account.clone(), comment_id, action));
+                Self::forget_post_scorer(comment_id, account.clone(), action);
             } else {
                 match action {
                     ScoringAction::UpvoteComment => {
@@ -266,7 +564,8 @@ impl<T: Trait> Module<T> {
                 let score_diff = Self::score_diff_for_action(social_account.reputation, action);
                 comment.score = comment.score.checked_add(score_diff as i32).ok_or(Error::<T>::CommentScoreOverflow)?;
                 Self::change_social_account_reputation(comment.created.account.clone(), account.clone(), score_diff, action)?;
-                <PostScoreByAccount<T>>::insert((account, comment_id, action), score_diff);
+                <PostScoreByAccount<T>>::insert((account.clone(), comment_id, action), score_diff);
+                Self::remember_post_scorer(comment_id, account.clone(), action);
             }
             <PostById<T>>::insert(comment_id, comment.clone());
         }
@@ -274,6 +573,66 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Reverses a single scorer's stored score diff for a post, mirroring the vote toggle-off
+    /// path. Intended to be called when a previously-scored post is later blocked, so its
+    /// accumulated score and the corresponding author reputation are subtracted back out.
+    pub fn revert_score(
+        scorer: T::AccountId,
+        post: &mut Post<T>,
+        action: ScoringAction,
+    ) -> DispatchResult {
+        let post_id = post.id;
+
+        if let Some(score_diff) = Self::post_score_by_account((scorer.clone(), post_id, action)) {
+            let reputation_diff = Self::account_reputation_diff_by_account((scorer.clone(), post.created.account.clone(), action))
+                .ok_or(Error::<T>::ReputationDiffNotFound)?;
+
+            if post.is_comment() {
+                post.score = post.score.checked_sub(score_diff as i32).ok_or(Error::<T>::CommentScoreUnderflow)?;
+            } else {
+                post.score = post.score.checked_sub(score_diff as i32).ok_or(Error::<T>::PostScoreUnderflow)?;
+                if let Some(space_id) = post.space_id {
+                    let mut space = Spaces::require_space(space_id)?;
+                    space.score = space.score.checked_sub(score_diff as i32).ok_or(Error::<T>::SpaceScoreUnderflow)?;
+                    <SpaceById<T>>::insert(space_id, space);
+                }
+            }
+
+            Self::change_social_account_reputation(post.created.account.clone(), scorer.clone(), -reputation_diff, action)?;
+            <PostScoreByAccount<T>>::remove((scorer.clone(), post_id, action));
+            Self::forget_post_scorer(post_id, scorer, action);
+            <PostById<T>>::insert(post_id, post.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Reverses every stored scorer's contribution to a post, used when the post is later blocked
+    /// so its accumulated score and the reputation it granted authors are fully reconciled.
+    pub fn revert_all_scores(post: &mut Post<T>) -> DispatchResult {
+        for (scorer, action) in Self::scorers_by_post(post.id) {
+            Self::revert_score(scorer, post, action)?;
+        }
+        <ScorersByPost<T>>::remove(post.id);
+        Ok(())
+    }
+
+    /// Records that `scorer` has scored a post via `action`, so `revert_all_scores` can find it.
+    fn remember_post_scorer(post_id: PostId, scorer: T::AccountId, action: ScoringAction) {
+        <ScorersByPost<T>>::mutate(post_id, |scorers| {
+            if !scorers.contains(&(scorer.clone(), action)) {
+                scorers.push((scorer, action));
+            }
+        });
+    }
+
+    /// Drops a `(scorer, action)` pair once its score contribution has been removed.
+    fn forget_post_scorer(post_id: PostId, scorer: T::AccountId, action: ScoringAction) {
+        <ScorersByPost<T>>::mutate(post_id, |scorers| {
+            scorers.retain(|pair| pair != &(scorer.clone(), action));
+        });
+    }
+
     pub fn change_social_account_reputation(
         account: T::AccountId,
         scorer: T::AccountId,
@@ -284,6 +643,8 @@ impl<T: Trait> Module<T> {
         // TODO return Ok(()) if score_diff == 0?
 
         let mut social_account = Profiles::get_or_new_social_account(account.clone());
+        let reputation_before = social_account.reputation;
+        let record_scorer = scorer.clone();
 
         if social_account.reputation as i64 + score_diff as i64 <= 1 {
             social_account.reputation = 1;
@@ -300,34 +661,155 @@ impl<T: Trait> Module<T> {
                 .ok_or(Error::<T>::ReputationUnderflow)?;
         }
 
-        if Self::account_reputation_diff_by_account((scorer.clone(), account.clone(), action)).is_some() {
-            <AccountReputationDiffByAccount<T>>::remove((scorer, account.clone(), action));
-        } else {
-            <AccountReputationDiffByAccount<T>>::insert((scorer, account.clone(), action), score_diff);
+        match action {
+            // Tips are cumulative: value was actually spent, so the per-scorer diff must keep
+            // accumulating rather than toggle off like a vote. A reversal (negative `score_diff`
+            // from `revert_score`) nets the entry back down, clearing it once it reaches zero.
+            ScoringAction::TipPost | ScoringAction::TipComment => {
+                <AccountReputationDiffByAccount<T>>::mutate_exists((scorer, account.clone(), action), |maybe_diff| {
+                    let acc = maybe_diff.unwrap_or(0).saturating_add(score_diff);
+                    *maybe_diff = if acc == 0 { None } else { Some(acc) };
+                });
+            }
+            _ => {
+                if Self::account_reputation_diff_by_account((scorer.clone(), account.clone(), action)).is_some() {
+                    <AccountReputationDiffByAccount<T>>::remove((scorer, account.clone(), action));
+                } else {
+                    <AccountReputationDiffByAccount<T>>::insert((scorer, account.clone(), action), score_diff);
+                }
+            }
         }
 
+        let now = <system::Module<T>>::block_number();
+
+        Self::push_reputation_record(&account, ReputationChangeRecord {
+            scorer: record_scorer,
+            action,
+            diff: score_diff,
+            reputation_before,
+            reputation_after: social_account.reputation,
+            block: now,
+        });
+
         <SocialAccountById<T>>::insert(account.clone(), social_account.clone());
+        <LastReputationTouch<T>>::insert(account.clone(), now);
 
         Self::deposit_event(RawEvent::AccountReputationChanged(account, action, social_account.reputation));
 
         Ok(())
     }
 
-    pub fn score_diff_for_action(reputation: u32, action: ScoringAction) -> i16 {
-        Self::smooth_reputation(reputation) as i16 * Self::weight_of_scoring_action(action)
+    /// Pushes a record into the account's bounded reputation history, dropping the oldest entry
+    /// once `MaxReputationHistoryLen` is reached (ring-buffer semantics).
+    fn push_reputation_record(account: &T::AccountId, record: ReputationChangeRecord<T>) {
+        let max_len = T::MaxReputationHistoryLen::get() as usize;
+        if max_len == 0 {
+            return;
+        }
+
+        <ReputationHistory<T>>::mutate(account, |history| {
+            if history.len() >= max_len {
+                history.remove(0);
+            }
+            history.push(record);
+        });
     }
 
-    fn smooth_reputation(reputation: u32) -> u8 {
-        log_2(reputation).map_or(1, |r| {
-            let d = (reputation as u64 - (2 as u64).pow(r)) * 100
-                / (2 as u64).pow(r);
+    /// Sweeps up to `MaxDecayAccountsPerBlock` accounts per block, decaying the reputation of
+    /// those idle for longer than `DecayPeriod` by `DecayPermill` of its current value (never
+    /// below the floor of `1`). The cursor advances round-robin so the whole set is covered.
+    fn decay_reputation_sweep() -> Weight {
+        let max = T::MaxDecayAccountsPerBlock::get();
+        if max == 0 {
+            return 0;
+        }
 
-            // We can safely cast this result to i16 because a score diff for u32::MAX is 32.
-            (((r + 1) * 100 + d as u32) / 100) as u8
-        })
+        let now = <system::Module<T>>::block_number();
+        let decay_period = T::DecayPeriod::get();
+        let permill = T::DecayPermill::get();
+
+        // Resume from the key after the last one swept, so accounts inserted or removed between
+        // blocks never shift a positional window and get skipped or decayed twice.
+        let batch: Vec<(T::AccountId, _)> = match Self::decay_cursor() {
+            Some(last_key) => <SocialAccountById<T>>::iter_from(last_key).take(max as usize).collect(),
+            None => <SocialAccountById<T>>::iter().take(max as usize).collect(),
+        };
+
+        // Reached the end of the set: restart from the first key next block.
+        if batch.is_empty() {
+            DecayCursor::kill();
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut reads = 0u64;
+        let mut writes = 0u64;
+        let mut last_account: Option<T::AccountId> = None;
+        for (account, mut social_account) in batch {
+            reads = reads.saturating_add(1);
+            last_account = Some(account.clone());
+
+            let idle = now.saturating_sub(Self::last_reputation_touch(&account)) > decay_period;
+            if idle && social_account.reputation > 1 {
+                let reputation_before = social_account.reputation;
+                let decayed = permill * social_account.reputation;
+                social_account.reputation = social_account.reputation.saturating_sub(decayed).max(1);
+
+                <SocialAccountById<T>>::insert(account.clone(), social_account.clone());
+                <LastReputationTouch<T>>::insert(account.clone(), now);
+
+                // Record the decay in the same audit trail as every other reputation change, and
+                // emit a dedicated event so indexers do not mistake it for a `FollowAccount`.
+                let diff = (social_account.reputation as i64 - reputation_before as i64)
+                    .max(i16::min_value() as i64) as i16;
+                Self::push_reputation_record(&account, ReputationChangeRecord {
+                    scorer: account.clone(),
+                    action: ScoringAction::Decay,
+                    diff,
+                    reputation_before,
+                    reputation_after: social_account.reputation,
+                    block: now,
+                });
+
+                Self::deposit_event(
+                    RawEvent::AccountReputationDecayed(account, social_account.reputation)
+                );
+                writes = writes.saturating_add(2);
+            }
+        }
+
+        match last_account {
+            Some(account) => DecayCursor::put(<SocialAccountById<T>>::hashed_key_for(account)),
+            None => DecayCursor::kill(),
+        }
+
+        T::DbWeight::get().reads_writes(reads + 1, writes + 1)
+    }
+
+    pub fn score_diff_for_action(reputation: u32, action: ScoringAction) -> i16 {
+        saturating_score(
+            clamp_influence(T::Curve::influence(reputation)),
+            Self::weight_of_scoring_action(action),
+        )
+    }
+
+    /// Log-damped multiplier applied to a tip's score gain: larger tips keep adding, but with
+    /// diminishing marginal reputation. The log is taken over the full balance width so tips on
+    /// high-decimal chains still scale with value instead of all saturating to the same factor.
+    fn tip_value_factor(amount: BalanceOf<T>) -> i16 {
+        let amount_u128: u128 = amount.saturated_into::<u128>();
+        if amount_u128 == 0 {
+            return 1;
+        }
+        // floor(log2(amount)) + 1; at most 128, so it always fits in `i16`.
+        let log2 = 127u32 - amount_u128.leading_zeros();
+        (log2 + 1) as i16
     }
 
     fn weight_of_scoring_action(action: ScoringAction) -> i16 {
+        if let Some(weight) = Self::action_weight_override(action) {
+            return weight;
+        }
+
         use ScoringAction::*;
         match action {
             UpvotePost => T::UpvotePostActionWeight::get(),
@@ -339,6 +821,65 @@ impl<T: Trait> Module<T> {
             ShareComment => T::ShareCommentActionWeight::get(),
             FollowSpace => T::FollowSpaceActionWeight::get(),
             FollowAccount => T::FollowAccountActionWeight::get(),
+            TipPost => T::TipPostActionWeight::get(),
+            TipComment => T::TipCommentActionWeight::get(),
+            // Decay is applied directly as a reputation delta, never weighted.
+            Decay => 0,
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::parameter_types;
+
+    parameter_types! {
+        pub const UnitSlope: u16 = 1;
+        pub const HighCap: u16 = 50_000;
+    }
+
+    type BigLinear = CappedLinearCurve<UnitSlope, HighCap>;
+
+    // The invariant every curve must uphold: clamped influence times any weight saturates into a
+    // valid `i16`, so `score_diff_for_action` can never overflow and flip a score's sign. The
+    // worst case is the largest influence against the largest/smallest representable weight.
+    fn assert_product_fits_i16<C: ReputationCurve>(reputation: u32) {
+        let influence = clamp_influence(C::influence(reputation));
+        assert!(influence >= 0);
+        for &weight in &[i16::max_value(), i16::min_value(), 1, -1, 0] {
+            let score = saturating_score(influence, weight);
+            let expect = (influence as i32 * weight as i32)
+                .max(i16::min_value() as i32)
+                .min(i16::max_value() as i32);
+            assert_eq!(score as i32, expect);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn log2_curve_product_stays_within_i16() {
+        assert_eq!(Log2Curve::influence(0), 1);
+        assert_product_fits_i16::<Log2Curve>(u32::max_value());
+    }
+
+    #[test]
+    fn sqrt_curve_is_the_integer_square_root() {
+        assert_eq!(SqrtCurve::influence(0), 1);
+        assert_eq!(SqrtCurve::influence(10_000), 100);
+        // sqrt(u32::MAX) ~= 65535 > i16::MAX, so the raw influence would wrap negative on cast.
+        assert!(SqrtCurve::influence(u32::max_value()) > i16::max_value() as u16);
+        assert_eq!(clamp_influence(SqrtCurve::influence(u32::max_value())), i16::max_value());
+        // influence 32767 * weight 32767 overflows a naive i16 multiply; it must saturate instead.
+        assert_eq!(saturating_score(i16::max_value(), i16::max_value()), i16::max_value());
+        assert_product_fits_i16::<SqrtCurve>(u32::max_value());
+    }
+
+    #[test]
+    fn capped_linear_curve_clamps_above_i16() {
+        assert_eq!(BigLinear::influence(0), 1);
+        // Cap of 50_000 exceeds i16::MAX, which is exactly the footgun `clamp_influence` guards.
+        assert_eq!(BigLinear::influence(u32::max_value()), 50_000);
+        assert_eq!(clamp_influence(BigLinear::influence(u32::max_value())), i16::max_value());
+        assert_eq!(saturating_score(i16::max_value(), i16::min_value()), i16::min_value());
+        assert_product_fits_i16::<BigLinear>(u32::max_value());
+    }
+}